@@ -0,0 +1,30 @@
+use thiserror::Error;
+
+/// Failures surfaced to the user as a clean exit rather than a panic.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// The origin URL could not be read — usually not a git repository.
+    #[error("could not read remote url via git (is this a git repository?)")]
+    GitNotFound,
+
+    /// The remote URL did not match any known hosting provider.
+    #[error("remote `{0}` is not a recognised hosting provider")]
+    UnsupportedRemote(String),
+
+    /// `--repo` was not shaped like `owner/project/repo`.
+    #[error("`{0}` is not a recognised repo spec (expected `owner/project/repo`)")]
+    InvalidRepoSpec(String),
+
+    /// The provider's credential environment variable is missing.
+    #[error("environment variable {0} is not set")]
+    MissingPat(String),
+
+    /// HEAD (or the requested commit) could not be resolved.
+    #[error("unable to determine repository HEAD")]
+    HeadUnavailable,
+
+    /// An error returned by the hosting provider's API, or any other failure
+    /// (JSON output, opening the browser, the webhook listener).
+    #[error(transparent)]
+    Api(#[from] anyhow::Error),
+}
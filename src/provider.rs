@@ -0,0 +1,88 @@
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::providers::{AzureProvider, GitHubProvider, GitLabProvider};
+
+/// Repository coordinates parsed out of a remote URL.
+///
+/// Azure DevOps addresses a repository by all three of `org`/`project`/`repo`;
+/// providers with only a two-level namespace (GitHub) leave `project` empty.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RepoComponents {
+    pub org: String,
+    pub project: String,
+    pub repo: String,
+}
+
+/// Information resolved for a commit: the pull request it belongs to together
+/// with any linked work items / issues.
+///
+/// The `*_url` fields are rendered by the originating [`HostingProvider`] from
+/// its own web base, so display and `--open` need not know provider specifics.
+#[derive(Serialize)]
+pub struct AchInfo {
+    pub pr: i32,
+    pub work_items: Vec<i32>,
+    pub pr_url: String,
+    pub work_item_urls: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+}
+
+impl AchInfo {
+    pub fn display(&self) {
+        print!("Pull-request #{}", self.pr);
+        if let Some(title) = &self.title {
+            print!(" {title}");
+        }
+        println!(" {}", self.pr_url);
+        for (work_item, url) in self.work_items.iter().zip(&self.work_item_urls) {
+            println!("Work-item #{} {}", work_item, url);
+        }
+    }
+}
+
+/// A git hosting provider (Azure DevOps, GitHub, GitLab, ...).
+///
+/// Detection (`matches`/`parse`) is stateless so the registry can probe an
+/// origin URL before any credentials are available; [`HostingProvider::configure`]
+/// then yields a ready-to-query instance bound to a concrete repository.
+#[async_trait::async_trait]
+pub trait HostingProvider {
+    /// Whether this provider recognises `url` as one of its remotes.
+    fn matches(&self, url: &str) -> bool;
+
+    /// Parse `url` into repository coordinates, or `None` if it does not match.
+    fn parse(&self, url: &str) -> Option<RepoComponents>;
+
+    /// Name of the environment variable this provider reads its credential
+    /// from (e.g. `ADO_PAT`, `GITHUB_TOKEN`, `GITLAB_TOKEN`).
+    fn token_env_var(&self) -> &'static str;
+
+    /// Bind the provider to a repository and credential, returning an instance
+    /// ready to answer [`HostingProvider::pull_request_for_commit`].
+    fn configure(&self, components: RepoComponents, pat: String) -> Result<Box<dyn HostingProvider>>;
+
+    /// Find the pull request whose commit list contains `head` and resolve its
+    /// linked work items / issues.
+    async fn pull_request_for_commit(&self, head: &str) -> Result<Option<AchInfo>>;
+
+    /// Resolve a specific pull request by id, skipping commit matching.
+    async fn pull_request_by_id(&self, id: i32) -> Result<Option<AchInfo>>;
+}
+
+/// The ordered set of providers probed against an origin URL. The first whose
+/// [`HostingProvider::matches`] returns `true` wins.
+pub fn registry() -> Vec<Box<dyn HostingProvider>> {
+    vec![
+        Box::new(AzureProvider::default()),
+        Box::new(GitHubProvider::default()),
+        Box::new(GitLabProvider::default()),
+    ]
+}
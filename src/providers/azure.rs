@@ -0,0 +1,332 @@
+use anyhow::Result;
+use azure_devops_rust_api::git::{models::GitPullRequest, Client, ClientBuilder};
+
+use crate::giturl;
+use crate::provider::{AchInfo, HostingProvider, RepoComponents};
+
+/// Azure DevOps (`dev.azure.com`) provider.
+///
+/// The detection half (`matches`/`parse`) is stateless; once selected it is
+/// [`configure`](HostingProvider::configure)d into an instance carrying the
+/// resolved [`RepoComponents`] and an authenticated [`Client`].
+#[derive(Default)]
+pub struct AzureProvider {
+    config: Option<AzureConfig>,
+}
+
+struct AzureConfig {
+    components: RepoComponents,
+    client: Client,
+}
+
+fn parse_azure_git_url(url: &str) -> Option<RepoComponents> {
+    let git = giturl::parse(url)?;
+    if !git.host.ends_with("dev.azure.com") {
+        return None;
+    }
+
+    match git.segments.as_slice() {
+        // ssh form: v3/org/project/repo
+        [v3, org, project, repo] if v3 == "v3" => Some(RepoComponents {
+            org: org.clone(),
+            project: project.clone(),
+            repo: repo.clone(),
+        }),
+        // https form: org/project/_git/repo
+        [org, project, git_marker, repo] if git_marker == "_git" => Some(RepoComponents {
+            org: org.clone(),
+            project: project.clone(),
+            repo: repo.clone(),
+        }),
+        _ => None,
+    }
+}
+
+impl AzureProvider {
+    fn config(&self) -> &AzureConfig {
+        self.config
+            .as_ref()
+            .expect("azure provider must be configured before querying")
+    }
+
+    async fn repo_pull_requests(&self) -> Result<Vec<GitPullRequest>> {
+        let AzureConfig { components, client } = self.config();
+        let client = client.pull_requests_client();
+        Ok(client
+            .get_pull_requests(
+                components.org.clone(),
+                components.repo.clone(),
+                components.project.clone(),
+            )
+            .await?
+            .value)
+    }
+
+    async fn pull_request_commit_ids(&self, pull_request: &GitPullRequest) -> Result<Vec<String>> {
+        let AzureConfig { components, client } = self.config();
+        let commits = client
+            .pull_request_commits_client()
+            .get_pull_request_commits(
+                components.org.clone(),
+                pull_request.repository.id.clone(),
+                pull_request.pull_request_id,
+                components.project.clone(),
+            )
+            .await?
+            .value;
+
+        Ok(commits
+            .into_iter()
+            .flat_map(|commit| commit.commit_id)
+            .collect())
+    }
+
+    async fn pull_request_work_item_ids(&self, pull_request: &GitPullRequest) -> Result<Vec<i32>> {
+        let AzureConfig { components, client } = self.config();
+        let work_items: Vec<i32> = client
+            .pull_request_work_items_client()
+            .list(
+                components.org.clone(),
+                components.repo.clone(),
+                pull_request.pull_request_id,
+                components.project.clone(),
+            )
+            .await?
+            .value
+            .into_iter()
+            .flat_map(|resource_ref| {
+                resource_ref
+                    .id
+                    .and_then(|id| id.parse::<i32>().ok())
+            })
+            .collect();
+
+        Ok(work_items)
+    }
+
+    async fn matching_pull_request(&self, head: &str) -> Result<Option<GitPullRequest>> {
+        for pull_request in self.repo_pull_requests().await? {
+            for commit in self.pull_request_commit_ids(&pull_request).await? {
+                if commit.eq(head) {
+                    return Ok(Some(pull_request));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    async fn build_info(&self, pull_request: GitPullRequest, commit: Option<String>) -> AchInfo {
+        let AzureConfig { components, .. } = self.config();
+        let pr = pull_request.pull_request_id;
+
+        let title = pull_request.title.clone();
+        let status = pull_request.status.as_ref().map(|s| format!("{s:?}"));
+        let author = pull_request
+            .created_by
+            .as_ref()
+            .and_then(|identity| identity.display_name.clone());
+
+        // Allow partial success i.e. only PR id
+        let work_items = self
+            .pull_request_work_item_ids(&pull_request)
+            .await
+            .unwrap_or_default();
+
+        let pr_url = format!(
+            "https://dev.azure.com/{}/{}/_git/{}/pullrequest/{}",
+            components.org, components.project, components.repo, pr
+        );
+        let work_item_urls = work_items
+            .iter()
+            .map(|id| {
+                format!(
+                    "https://dev.azure.com/{}/{}/_workitems/edit/{}",
+                    components.org, components.project, id
+                )
+            })
+            .collect();
+
+        AchInfo {
+            pr,
+            work_items,
+            pr_url,
+            work_item_urls,
+            title,
+            status,
+            author,
+            commit,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl HostingProvider for AzureProvider {
+    fn matches(&self, url: &str) -> bool {
+        url.contains("dev.azure.com")
+    }
+
+    fn parse(&self, url: &str) -> Option<RepoComponents> {
+        parse_azure_git_url(url)
+    }
+
+    fn token_env_var(&self) -> &'static str {
+        "ADO_PAT"
+    }
+
+    fn configure(&self, components: RepoComponents, pat: String) -> Result<Box<dyn HostingProvider>> {
+        let credential = azure_devops_rust_api::Credential::Pat(pat);
+        let client = ClientBuilder::new(credential).build();
+        Ok(Box::new(AzureProvider {
+            config: Some(AzureConfig { components, client }),
+        }))
+    }
+
+    async fn pull_request_for_commit(&self, head: &str) -> Result<Option<AchInfo>> {
+        let Some(pull_request) = self.matching_pull_request(head).await? else {
+            return Ok(None);
+        };
+        Ok(Some(self.build_info(pull_request, Some(head.to_string())).await))
+    }
+
+    async fn pull_request_by_id(&self, id: i32) -> Result<Option<AchInfo>> {
+        let AzureConfig { components, client } = self.config();
+        let pull_request = client
+            .pull_requests_client()
+            .get_pull_request_by_id(components.org.clone(), id, components.project.clone())
+            .await?;
+        Ok(Some(self.build_info(pull_request, None).await))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_valid_ssh_url() {
+        let url = "git@ssh.dev.azure.com:v3/MyOrg/MyProject/MyRepo";
+        let result = parse_azure_git_url(url);
+        assert_eq!(
+            result,
+            Some(RepoComponents {
+                org: "MyOrg".to_string(),
+                project: "MyProject".to_string(),
+                repo: "MyRepo".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn parses_valid_https_url() {
+        let url = "https://MyOrg@dev.azure.com/MyOrg/MyProject/_git/MyRepo";
+        let result = parse_azure_git_url(url);
+        assert_eq!(
+            result,
+            Some(RepoComponents {
+                org: "MyOrg".to_string(),
+                project: "MyProject".to_string(),
+                repo: "MyRepo".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn fails_on_malformed_ssh_url() {
+        let url = "git@ssh.dev.azure.com:MyOrg/MyProject/MyRepo";
+        assert_eq!(parse_azure_git_url(url), None);
+    }
+
+    #[test]
+    fn accepts_https_url_without_user() {
+        // The path carries the coordinates, so a missing `user@` is fine now.
+        let url = "https://dev.azure.com/MyOrg/MyProject/_git/MyRepo";
+        assert_eq!(
+            parse_azure_git_url(url),
+            Some(RepoComponents {
+                org: "MyOrg".to_string(),
+                project: "MyProject".to_string(),
+                repo: "MyRepo".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn accepts_git_suffix() {
+        let url = "https://MyOrg@dev.azure.com/MyOrg/MyProject/_git/MyRepo.git";
+        assert_eq!(
+            parse_azure_git_url(url),
+            Some(RepoComponents {
+                org: "MyOrg".to_string(),
+                project: "MyProject".to_string(),
+                repo: "MyRepo".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn fails_on_unrelated_url() {
+        let url = "https://github.com/user/repo.git";
+        assert_eq!(parse_azure_git_url(url), None);
+    }
+
+    #[test]
+    fn handles_underscore_in_names() {
+        let url = "git@ssh.dev.azure.com:v3/Org_Name/Project_Name/Repo_Name";
+        let result = parse_azure_git_url(url);
+        assert_eq!(
+            result,
+            Some(RepoComponents {
+                org: "Org_Name".to_string(),
+                project: "Project_Name".to_string(),
+                repo: "Repo_Name".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn handles_dash_in_names() {
+        let url = "https://org-name@dev.azure.com/org-name/proj-name/_git/repo-name";
+        let result = parse_azure_git_url(url);
+        assert_eq!(
+            result,
+            Some(RepoComponents {
+                org: "org-name".to_string(),
+                project: "proj-name".to_string(),
+                repo: "repo-name".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn mismatched_org_in_https_still_parses() {
+        // ORG part in the URL vs path can differ but parsing is driven by the path
+        let url = "https://user@dev.azure.com/SomeOrg/SomeProject/_git/SomeRepo";
+        let result = parse_azure_git_url(url);
+        assert_eq!(
+            result,
+            Some(RepoComponents {
+                org: "SomeOrg".to_string(),
+                project: "SomeProject".to_string(),
+                repo: "SomeRepo".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn trailing_slash_is_ignored() {
+        let url = "https://org@dev.azure.com/org/project/_git/repo/";
+        assert_eq!(
+            parse_azure_git_url(url),
+            Some(RepoComponents {
+                org: "org".to_string(),
+                project: "project".to_string(),
+                repo: "repo".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn empty_string_fails() {
+        assert_eq!(parse_azure_git_url(""), None);
+    }
+}
@@ -0,0 +1,292 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::giturl;
+use crate::provider::{AchInfo, HostingProvider, RepoComponents};
+
+/// GitHub (`github.com` and self-hosted GitHub Enterprise) provider.
+///
+/// The API base defaults to the public endpoint; configure `GITHUB_API_URL`
+/// (e.g. `https://github.example.com/api/v3`) and `GITHUB_WEB_URL` (e.g.
+/// `https://github.example.com`) to target an Enterprise instance. Detection
+/// (`matches`/`parse`) is driven off the host of `GITHUB_WEB_URL`, so an
+/// Enterprise origin is only recognised once its web URL is configured.
+pub struct GitHubProvider {
+    api_base: String,
+    web_base: String,
+    web_host: String,
+    config: Option<GitHubConfig>,
+}
+
+struct GitHubConfig {
+    components: RepoComponents,
+    client: reqwest::Client,
+}
+
+impl Default for GitHubProvider {
+    fn default() -> Self {
+        let web_base = std::env::var("GITHUB_WEB_URL")
+            .unwrap_or_else(|_| "https://github.com".to_string());
+        let web_host = giturl::parse(&web_base)
+            .map(|git| git.host)
+            .unwrap_or_else(|| "github.com".to_string());
+        Self {
+            api_base: std::env::var("GITHUB_API_URL")
+                .unwrap_or_else(|_| "https://api.github.com".to_string()),
+            web_base,
+            web_host,
+            config: None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct PullRef {
+    number: i32,
+    title: Option<String>,
+    state: Option<String>,
+    user: Option<User>,
+    body: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct User {
+    login: String,
+}
+
+/// Keywords GitHub recognises as linking a pull request to an issue it
+/// closes on merge (see "Linking a pull request to an issue").
+const CLOSING_KEYWORDS: &[&str] = &[
+    "close", "closes", "closed", "fix", "fixes", "fixed", "resolve", "resolves", "resolved",
+];
+
+/// Issue numbers this pull request's body closes, per GitHub's closing-keyword
+/// syntax (`Fixes #123`, `Closes #45`, ...). Only same-repository `#N`
+/// references are recognised; cross-repo `owner/repo#N` links are not.
+fn closing_issue_numbers(body: Option<&str>) -> Vec<i32> {
+    let Some(body) = body else {
+        return vec![];
+    };
+    let mut words = body.split_whitespace().peekable();
+    let mut issues = vec![];
+    while let Some(word) = words.next() {
+        let keyword = word.trim_end_matches(':').to_ascii_lowercase();
+        if !CLOSING_KEYWORDS.contains(&keyword.as_str()) {
+            continue;
+        }
+        if let Some(number) = words
+            .peek()
+            .and_then(|reference| reference.strip_prefix('#'))
+            .map(|digits| digits.trim_end_matches(|c: char| c.is_ascii_punctuation()))
+            .and_then(|digits| digits.parse::<i32>().ok())
+        {
+            issues.push(number);
+        }
+    }
+    issues
+}
+
+fn parse_github_url(url: &str, web_host: &str) -> Option<RepoComponents> {
+    let git = giturl::parse(url)?;
+    if git.host != web_host {
+        return None;
+    }
+    match git.segments.as_slice() {
+        [owner, repo] => Some(RepoComponents {
+            org: owner.clone(),
+            project: String::new(),
+            repo: repo.clone(),
+        }),
+        _ => None,
+    }
+}
+
+impl GitHubProvider {
+    fn config(&self) -> &GitHubConfig {
+        self.config
+            .as_ref()
+            .expect("github provider must be configured before querying")
+    }
+
+    fn build_info(&self, pull: PullRef, commit: Option<String>) -> AchInfo {
+        let GitHubConfig { components, .. } = self.config();
+        let pr_url = format!(
+            "{}/{}/{}/pull/{}",
+            self.web_base, components.org, components.repo, pull.number
+        );
+        // GitHub has no first-class "linked issues" API; it closes issues
+        // referenced by closing keywords in the PR body, so mine those.
+        let work_items = closing_issue_numbers(pull.body.as_deref());
+        let work_item_urls = work_items
+            .iter()
+            .map(|id| {
+                format!(
+                    "{}/{}/{}/issues/{}",
+                    self.web_base, components.org, components.repo, id
+                )
+            })
+            .collect();
+        AchInfo {
+            pr: pull.number,
+            work_items,
+            pr_url,
+            work_item_urls,
+            title: pull.title,
+            status: pull.state,
+            author: pull.user.map(|user| user.login),
+            commit,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl HostingProvider for GitHubProvider {
+    fn matches(&self, url: &str) -> bool {
+        giturl::parse(url).is_some_and(|git| git.host == self.web_host)
+    }
+
+    fn parse(&self, url: &str) -> Option<RepoComponents> {
+        parse_github_url(url, &self.web_host)
+    }
+
+    fn token_env_var(&self) -> &'static str {
+        "GITHUB_TOKEN"
+    }
+
+    fn configure(&self, components: RepoComponents, pat: String) -> Result<Box<dyn HostingProvider>> {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            reqwest::header::AUTHORIZATION,
+            format!("Bearer {pat}").parse().context("building auth header")?,
+        );
+        headers.insert(
+            reqwest::header::ACCEPT,
+            "application/vnd.github+json"
+                .parse()
+                .context("building accept header")?,
+        );
+        let client = reqwest::Client::builder()
+            .user_agent("ach")
+            .default_headers(headers)
+            .build()
+            .context("building github http client")?;
+        Ok(Box::new(GitHubProvider {
+            api_base: self.api_base.clone(),
+            web_base: self.web_base.clone(),
+            web_host: self.web_host.clone(),
+            config: Some(GitHubConfig { components, client }),
+        }))
+    }
+
+    async fn pull_request_for_commit(&self, head: &str) -> Result<Option<AchInfo>> {
+        let GitHubConfig { components, client } = self.config();
+        // GitHub answers "which PRs contain this commit" directly.
+        let url = format!(
+            "{}/repos/{}/{}/commits/{}/pulls",
+            self.api_base, components.org, components.repo, head
+        );
+        let pulls: Vec<PullRef> = client
+            .get(url)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await
+            .context("decoding pull requests for commit")?;
+
+        let Some(pull) = pulls.into_iter().next() else {
+            return Ok(None);
+        };
+        Ok(Some(self.build_info(pull, Some(head.to_string()))))
+    }
+
+    async fn pull_request_by_id(&self, id: i32) -> Result<Option<AchInfo>> {
+        let GitHubConfig { components, client } = self.config();
+        let url = format!(
+            "{}/repos/{}/{}/pulls/{}",
+            self.api_base, components.org, components.repo, id
+        );
+        let pull: PullRef = client
+            .get(url)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await
+            .context("decoding pull request")?;
+
+        Ok(Some(self.build_info(pull, None)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_https_url_with_git_suffix() {
+        assert_eq!(
+            parse_github_url("https://github.com/user/repo.git", "github.com"),
+            Some(RepoComponents {
+                org: "user".to_string(),
+                project: String::new(),
+                repo: "repo".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn parses_scp_style_url() {
+        assert_eq!(
+            parse_github_url("git@github.com:user/repo.git", "github.com"),
+            Some(RepoComponents {
+                org: "user".to_string(),
+                project: String::new(),
+                repo: "repo".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_non_github_url() {
+        assert_eq!(parse_github_url("https://dev.azure.com/o/p/_git/r", "github.com"), None);
+    }
+
+    #[test]
+    fn parses_enterprise_url_against_configured_host() {
+        assert_eq!(
+            parse_github_url("https://github.acme.com/user/repo.git", "github.acme.com"),
+            Some(RepoComponents {
+                org: "user".to_string(),
+                project: String::new(),
+                repo: "repo".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_lookalike_host() {
+        assert_eq!(
+            parse_github_url("https://notgithub.com/user/repo.git", "github.com"),
+            None
+        );
+    }
+
+    #[test]
+    fn finds_closing_issue_with_various_keywords() {
+        assert_eq!(
+            closing_issue_numbers(Some("Fixes #12 and closes #34, Resolved: #56")),
+            vec![12, 34, 56]
+        );
+    }
+
+    #[test]
+    fn ignores_unrelated_hash_references() {
+        assert_eq!(closing_issue_numbers(Some("See #12 for context")), vec![]);
+    }
+
+    #[test]
+    fn no_body_closes_nothing() {
+        assert_eq!(closing_issue_numbers(None), vec![]);
+    }
+}
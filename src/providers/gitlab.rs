@@ -0,0 +1,267 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::giturl;
+use crate::provider::{AchInfo, HostingProvider, RepoComponents};
+
+/// GitLab (`gitlab.com` and self-hosted instances) provider.
+///
+/// The API base defaults to the public endpoint; configure `GITLAB_API_URL`
+/// (e.g. `https://gitlab.example.com/api/v4`) and `GITLAB_WEB_URL` (e.g.
+/// `https://gitlab.example.com`) to target a self-hosted instance. Detection
+/// (`matches`/`parse`) is driven off the host of `GITLAB_WEB_URL`, so a
+/// self-hosted origin is only recognised once its web URL is configured.
+pub struct GitLabProvider {
+    api_base: String,
+    web_base: String,
+    web_host: String,
+    config: Option<GitLabConfig>,
+}
+
+struct GitLabConfig {
+    components: RepoComponents,
+    client: reqwest::Client,
+}
+
+impl Default for GitLabProvider {
+    fn default() -> Self {
+        let web_base = std::env::var("GITLAB_WEB_URL")
+            .unwrap_or_else(|_| "https://gitlab.com".to_string());
+        let web_host = giturl::parse(&web_base)
+            .map(|git| git.host)
+            .unwrap_or_else(|| "gitlab.com".to_string());
+        Self {
+            api_base: std::env::var("GITLAB_API_URL")
+                .unwrap_or_else(|_| "https://gitlab.com/api/v4".to_string()),
+            web_base,
+            web_host,
+            config: None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct MergeRequest {
+    iid: i32,
+    title: Option<String>,
+    state: Option<String>,
+    author: Option<Author>,
+}
+
+#[derive(Deserialize)]
+struct Author {
+    username: String,
+}
+
+#[derive(Deserialize)]
+struct IssueRef {
+    iid: i32,
+}
+
+/// GitLab groups a project under a (possibly nested) namespace, so the full
+/// path after the host is the project identifier. We keep the last segment as
+/// `repo` and the leading namespace as `org`.
+fn parse_gitlab_url(url: &str, web_host: &str) -> Option<RepoComponents> {
+    let git = giturl::parse(url)?;
+    if git.host != web_host {
+        return None;
+    }
+    let (repo, namespace) = git.segments.split_last()?;
+    if namespace.is_empty() {
+        return None;
+    }
+    Some(RepoComponents {
+        org: namespace.join("/"),
+        project: String::new(),
+        repo: repo.clone(),
+    })
+}
+
+impl GitLabProvider {
+    fn config(&self) -> &GitLabConfig {
+        self.config
+            .as_ref()
+            .expect("gitlab provider must be configured before querying")
+    }
+
+    /// URL-encoded `namespace/project` path GitLab expects for project ids.
+    fn project_path(&self, components: &RepoComponents) -> String {
+        let path = format!("{}/{}", components.org, components.repo);
+        path.replace('/', "%2F")
+    }
+
+    fn build_info(&self, mr: MergeRequest, work_items: Vec<i32>, commit: Option<String>) -> AchInfo {
+        let GitLabConfig { components, .. } = self.config();
+        let pr_url = format!(
+            "{}/{}/{}/-/merge_requests/{}",
+            self.web_base, components.org, components.repo, mr.iid
+        );
+        let work_item_urls = work_items
+            .iter()
+            .map(|id| {
+                format!(
+                    "{}/{}/{}/-/issues/{}",
+                    self.web_base, components.org, components.repo, id
+                )
+            })
+            .collect();
+        AchInfo {
+            pr: mr.iid,
+            work_items,
+            pr_url,
+            work_item_urls,
+            title: mr.title,
+            status: mr.state,
+            author: mr.author.map(|author| author.username),
+            commit,
+        }
+    }
+
+    /// Issues closed by the merge request — the GitLab analogue of work items.
+    async fn closes_issues(&self, project: &str, iid: i32) -> Vec<i32> {
+        let GitLabConfig { client, .. } = self.config();
+        let url = format!(
+            "{}/projects/{}/merge_requests/{}/closes_issues",
+            self.api_base, project, iid
+        );
+        match client.get(url).send().await.and_then(|r| r.error_for_status()) {
+            Ok(response) => response
+                .json::<Vec<IssueRef>>()
+                .await
+                .map(|issues| issues.into_iter().map(|issue| issue.iid).collect())
+                .unwrap_or_default(),
+            Err(_) => vec![],
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl HostingProvider for GitLabProvider {
+    fn matches(&self, url: &str) -> bool {
+        giturl::parse(url).is_some_and(|git| git.host == self.web_host)
+    }
+
+    fn parse(&self, url: &str) -> Option<RepoComponents> {
+        parse_gitlab_url(url, &self.web_host)
+    }
+
+    fn token_env_var(&self) -> &'static str {
+        "GITLAB_TOKEN"
+    }
+
+    fn configure(&self, components: RepoComponents, pat: String) -> Result<Box<dyn HostingProvider>> {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            "PRIVATE-TOKEN",
+            pat.parse().context("building private-token header")?,
+        );
+        let client = reqwest::Client::builder()
+            .user_agent("ach")
+            .default_headers(headers)
+            .build()
+            .context("building gitlab http client")?;
+        Ok(Box::new(GitLabProvider {
+            api_base: self.api_base.clone(),
+            web_base: self.web_base.clone(),
+            web_host: self.web_host.clone(),
+            config: Some(GitLabConfig { components, client }),
+        }))
+    }
+
+    async fn pull_request_for_commit(&self, head: &str) -> Result<Option<AchInfo>> {
+        let GitLabConfig { components, client } = self.config();
+        let project = self.project_path(components);
+
+        let url = format!(
+            "{}/projects/{}/repository/commits/{}/merge_requests",
+            self.api_base, project, head
+        );
+        let merge_requests: Vec<MergeRequest> = client
+            .get(url)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await
+            .context("decoding merge requests for commit")?;
+
+        let Some(mr) = merge_requests.into_iter().next() else {
+            return Ok(None);
+        };
+
+        let work_items = self.closes_issues(&project, mr.iid).await;
+        Ok(Some(self.build_info(mr, work_items, Some(head.to_string()))))
+    }
+
+    async fn pull_request_by_id(&self, id: i32) -> Result<Option<AchInfo>> {
+        let GitLabConfig { components, client } = self.config();
+        let project = self.project_path(components);
+
+        let url = format!("{}/projects/{}/merge_requests/{}", self.api_base, project, id);
+        let mr: MergeRequest = client
+            .get(url)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await
+            .context("decoding merge request")?;
+
+        let work_items = self.closes_issues(&project, mr.iid).await;
+        Ok(Some(self.build_info(mr, work_items, None)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_nested_group_path() {
+        assert_eq!(
+            parse_gitlab_url("https://gitlab.com/group/subgroup/repo.git", "gitlab.com"),
+            Some(RepoComponents {
+                org: "group/subgroup".to_string(),
+                project: String::new(),
+                repo: "repo".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn parses_scp_style_url() {
+        assert_eq!(
+            parse_gitlab_url("git@gitlab.com:group/repo.git", "gitlab.com"),
+            Some(RepoComponents {
+                org: "group".to_string(),
+                project: String::new(),
+                repo: "repo".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_non_gitlab_url() {
+        assert_eq!(parse_gitlab_url("https://github.com/user/repo.git", "gitlab.com"), None);
+    }
+
+    #[test]
+    fn parses_self_hosted_url_against_configured_host() {
+        assert_eq!(
+            parse_gitlab_url("https://gitlab.acme.com/group/repo.git", "gitlab.acme.com"),
+            Some(RepoComponents {
+                org: "group".to_string(),
+                project: String::new(),
+                repo: "repo".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_lookalike_host() {
+        assert_eq!(
+            parse_gitlab_url("https://notgitlab.com/group/repo.git", "gitlab.com"),
+            None
+        );
+    }
+}
@@ -0,0 +1,7 @@
+mod azure;
+mod github;
+mod gitlab;
+
+pub use azure::AzureProvider;
+pub use github::GitHubProvider;
+pub use gitlab::GitLabProvider;
@@ -0,0 +1,170 @@
+use std::net::SocketAddr;
+
+use anyhow::Result;
+use axum::{
+    body::Bytes,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    routing::post,
+    Json, Router,
+};
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::Sha256;
+
+use crate::provider::{registry, AchInfo};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Shared secret needed to verify deliveries. The credential used to query
+/// the matched hosting provider is read per-delivery from that provider's own
+/// [`HostingProvider::token_env_var`].
+#[derive(Clone)]
+struct ServeState {
+    secret: String,
+}
+
+/// The subset of a provider push payload we consume: the repository it came
+/// from and the tip commit that was pushed.
+///
+/// `clone_url` is GitHub's field name; GitLab's equivalent payload carries the
+/// same URL under `git_http_url`.
+#[derive(Deserialize)]
+struct PushEvent {
+    after: String,
+    repository: Repository,
+}
+
+#[derive(Deserialize)]
+struct Repository {
+    #[serde(alias = "git_http_url")]
+    clone_url: String,
+}
+
+/// Start the webhook listener on `listen`, blocking until terminated.
+pub async fn run(listen: &str) -> Result<()> {
+    let secret = std::env::var("WEBHOOK_SECRET")
+        .map_err(|_| anyhow::anyhow!("environment variable WEBHOOK_SECRET should be set"))?;
+
+    let state = ServeState { secret };
+    let app = Router::new()
+        .route("/webhook", post(webhook))
+        .with_state(state);
+
+    let addr: SocketAddr = listen.parse()?;
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    axum::serve(listener, app).await?;
+    Ok(())
+}
+
+/// Verify `signature` (`sha256=<hex>`) as the HMAC-SHA256 of `body` under
+/// `secret`, using a constant-time comparison. This is GitHub's scheme.
+fn verify_signature(secret: &str, body: &[u8], signature: Option<&str>) -> bool {
+    let Some(digest) = signature.and_then(|value| value.strip_prefix("sha256=")) else {
+        return false;
+    };
+    let Ok(expected) = hex::decode(digest) else {
+        return false;
+    };
+    let mut mac =
+        HmacSha256::new_from_slice(secret.as_bytes()).expect("hmac accepts any key length");
+    mac.update(body);
+    mac.verify_slice(&expected).is_ok()
+}
+
+/// Verify `token` against `secret` in constant time. This is GitLab's scheme:
+/// a plaintext shared token sent as-is, unlike GitHub's HMAC signature.
+fn verify_gitlab_token(secret: &str, token: Option<&str>) -> bool {
+    let Some(token) = token else {
+        return false;
+    };
+    let secret = secret.as_bytes();
+    let token = token.as_bytes();
+    secret.len() == token.len()
+        && secret
+            .iter()
+            .zip(token)
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+/// Authenticate a delivery under whichever scheme its headers carry: GitHub's
+/// HMAC-signed `X-Hub-Signature-256`, or GitLab's plaintext `X-Gitlab-Token`.
+fn authenticate(secret: &str, headers: &HeaderMap, body: &[u8]) -> bool {
+    if let Some(signature) = headers
+        .get("X-Hub-Signature-256")
+        .and_then(|value| value.to_str().ok())
+    {
+        return verify_signature(secret, body, Some(signature));
+    }
+    let token = headers
+        .get("X-Gitlab-Token")
+        .and_then(|value| value.to_str().ok());
+    verify_gitlab_token(secret, token)
+}
+
+/// Handle a push delivery: authenticate it under the sending provider's
+/// scheme, resolve the PR/work-items for the pushed tip, and answer with the
+/// `AchInfo` as JSON.
+async fn webhook(
+    State(state): State<ServeState>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Json<Option<AchInfo>>, StatusCode> {
+    if !authenticate(&state.secret, &headers, &body) {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
+    let event: PushEvent = serde_json::from_slice(&body).map_err(|_| StatusCode::BAD_REQUEST)?;
+
+    let provider = registry()
+        .into_iter()
+        .find(|provider| provider.matches(&event.repository.clone_url))
+        .ok_or(StatusCode::BAD_REQUEST)?;
+    let components = provider
+        .parse(&event.repository.clone_url)
+        .ok_or(StatusCode::BAD_REQUEST)?;
+    let pat = std::env::var(provider.token_env_var())
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let provider = provider
+        .configure(components, pat)
+        .map_err(|_| StatusCode::BAD_GATEWAY)?;
+
+    let info = provider
+        .pull_request_for_commit(&event.after)
+        .await
+        .map_err(|_| StatusCode::BAD_GATEWAY)?;
+
+    Ok(Json(info))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_missing_signature() {
+        assert!(!verify_signature("secret", b"payload", None));
+    }
+
+    #[test]
+    fn rejects_tampered_body() {
+        // sha256=... for "payload" under "secret"; a different body must fail.
+        let mut mac = HmacSha256::new_from_slice(b"secret").unwrap();
+        mac.update(b"payload");
+        let signature = format!("sha256={}", hex::encode(mac.finalize().into_bytes()));
+        assert!(verify_signature("secret", b"payload", Some(&signature)));
+        assert!(!verify_signature("secret", b"tampered", Some(&signature)));
+    }
+
+    #[test]
+    fn accepts_matching_gitlab_token() {
+        assert!(verify_gitlab_token("secret", Some("secret")));
+    }
+
+    #[test]
+    fn rejects_mismatched_or_missing_gitlab_token() {
+        assert!(!verify_gitlab_token("secret", Some("wrong")));
+        assert!(!verify_gitlab_token("secret", None));
+    }
+}